@@ -0,0 +1,4 @@
+pub mod models;
+pub mod routes;
+
+pub use models::logistic_regression::LogisticRegression;
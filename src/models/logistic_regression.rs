@@ -1,10 +1,26 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct LogisticRegression {
     pub weights: Vec<f64>,
     pub bias: f64,
     pub learning_rate: f64,
+    // Força da regularização L2 (ridge). Zero desativa a penalização.
+    #[serde(default)]
+    pub lambda: f64,
+    // Tolerância de convergência: interrompe as épocas quando a variação média
+    // absoluta dos pesos fica abaixo deste valor. Zero desativa a parada antecipada.
+    #[serde(default)]
+    pub tolerance: f64,
+    // Quando verdadeiro, as features são padronizadas ((x - média) / desvio) antes
+    // de alcançarem os pesos, tanto no treino quanto na inferência.
+    #[serde(default)]
+    pub standardize: bool,
+    // Estatísticas por feature aprendidas no treino e reaplicadas na predição.
+    #[serde(default)]
+    pub means: Vec<f64>,
+    #[serde(default)]
+    pub stds: Vec<f64>,
     #[serde(default)]
     pub initialized: bool,
 }
@@ -16,24 +32,62 @@ impl LogisticRegression {
             weights,
             bias: 0.0, // Inicializado com zero
             learning_rate,
+            lambda: 0.0,
+            tolerance: 0.0,
+            standardize: false,
+            means: Vec::new(),
+            stds: Vec::new(),
             initialized: false,
         }
     }
 
     pub fn train(&mut self, x: &[Vec<f64>], y: &[f64], epochs: usize) {
-        for _ in 0..epochs {
+        self.train_with_progress(x, y, epochs, |_| {});
+    }
+
+    /// Treina o modelo notificando o número de épocas concluídas a cada iteração,
+    /// permitindo que chamadores assíncronos acompanhem o progresso do aprendizado.
+    pub fn train_with_progress<F>(&mut self, x: &[Vec<f64>], y: &[f64], epochs: usize, mut on_epoch: F)
+    where
+        F: FnMut(usize),
+    {
+        // Ajusta as estatísticas de padronização sobre a matriz de treino
+        if self.standardize {
+            self.fit_standardizer(x);
+        }
+
+        for epoch in 0..epochs {
+            // Acumula a variação absoluta dos pesos para avaliar a convergência
+            let mut total_change = 0.0;
+
             for (features, &target) in x.iter().zip(y.iter()) {
+                // Usa as features padronizadas tanto na predição quanto no gradiente,
+                // de modo que `error * x_j` seja avaliado na mesma escala que o
+                // somatório ponderado que a atualização pretende otimizar.
+                let scaled = self.standardize_features(features);
                 let prediction = self.sigmoid(self.weighted_sum(features));
                 let error = prediction - target;
 
-                // Atualiza os pesos
-                for (weight, &feature) in self.weights.iter_mut().zip(features.iter()) {
-                    *weight -= self.learning_rate * error * feature;
+                // Atualiza os pesos com penalização L2 (o bias não é penalizado)
+                for (weight, &feature) in self.weights.iter_mut().zip(scaled.iter()) {
+                    let update = self.learning_rate * (error * feature + self.lambda * *weight);
+                    *weight -= update;
+                    total_change += update.abs();
                 }
 
                 // Atualiza o bias
                 self.bias -= self.learning_rate * error;
             }
+
+            on_epoch(epoch + 1);
+
+            // Parada antecipada quando os pesos praticamente não mudam mais
+            if self.tolerance > 0.0 && !self.weights.is_empty() && !x.is_empty() {
+                let mean_change = total_change / (self.weights.len() * x.len()) as f64;
+                if mean_change < self.tolerance {
+                    break;
+                }
+            }
         }
         self.initialized = true;
     }
@@ -51,14 +105,65 @@ impl LogisticRegression {
     }
 
     pub fn weighted_sum(&self, features: &[f64]) -> f64 {
+        let scaled = self.standardize_features(features);
         self.weights
             .iter()
-            .zip(features)
+            .zip(scaled.iter())
             .map(|(w, xi)| w * xi)
             .sum::<f64>()
             + self.bias // Adicionando o bias ao somatório ponderado
     }
 
+    /// Calcula média e desvio padrão por feature sobre a matriz de treino.
+    fn fit_standardizer(&mut self, x: &[Vec<f64>]) {
+        let n_features = self.weights.len();
+        let n = x.len() as f64;
+        if n == 0.0 {
+            return;
+        }
+
+        let mut means = vec![0.0; n_features];
+        for sample in x {
+            for (m, &value) in means.iter_mut().zip(sample.iter()) {
+                *m += value;
+            }
+        }
+        for m in &mut means {
+            *m /= n;
+        }
+
+        let mut stds = vec![0.0; n_features];
+        for sample in x {
+            for (s, (&value, &mean)) in stds.iter_mut().zip(sample.iter().zip(means.iter())) {
+                let d = value - mean;
+                *s += d * d;
+            }
+        }
+        for s in &mut stds {
+            *s = (*s / n).sqrt();
+        }
+
+        self.means = means;
+        self.stds = stds;
+    }
+
+    /// Aplica a padronização aprendida (se houver) às features de entrada,
+    /// substituindo desvio padrão zero por 1.0 para evitar divisão por zero.
+    fn standardize_features(&self, features: &[f64]) -> Vec<f64> {
+        if !self.standardize || self.means.is_empty() {
+            return features.to_vec();
+        }
+
+        features
+            .iter()
+            .enumerate()
+            .map(|(j, &value)| {
+                let std = if self.stds[j] == 0.0 { 1.0 } else { self.stds[j] };
+                (value - self.means[j]) / std
+            })
+            .collect()
+    }
+
     pub fn sigmoid(&self, z: f64) -> f64 {
         1.0 / (1.0 + (-z).exp())
     }
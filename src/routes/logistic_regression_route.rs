@@ -0,0 +1,678 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use axum::{
+    extract::{FromRef, Path, State},
+    routing::{get, post},
+    Json, Router,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::{Serialize, Deserialize};
+use tokio::sync::Mutex;
+use crate::models::logistic_regression::LogisticRegression;
+
+/// Estado compartilhado da aplicação: o modelo atual e os jobs de aprendizado
+/// em andamento, cada um atrás do seu próprio lock para que o treinamento em
+/// segundo plano nunca bloqueie o tráfego de predição.
+#[derive(Clone)]
+pub struct AppState {
+    model: Arc<Mutex<LogisticRegression>>,
+    jobs: Arc<Mutex<HashMap<String, LearningJob>>>,
+    counter: Arc<AtomicU64>,
+}
+
+impl FromRef<AppState> for Arc<Mutex<LogisticRegression>> {
+    fn from_ref(state: &AppState) -> Self {
+        state.model.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<Mutex<HashMap<String, LearningJob>>> {
+    fn from_ref(state: &AppState) -> Self {
+        state.jobs.clone()
+    }
+}
+
+/// Estados possíveis do ciclo de vida de um treinamento.
+#[derive(Debug, Clone, Serialize)]
+pub enum LearningStatus {
+    Idle,
+    Learning,
+    Ready,
+    Failed(String),
+}
+
+/// Um treinamento em andamento ou concluído, acompanhado pelo seu `job_id`.
+pub struct LearningJob {
+    status: LearningStatus,
+    epochs_completed: Arc<AtomicUsize>,
+    total_epochs: usize,
+}
+
+/// Erros que podem ocorrer nas operações da API
+#[derive(Debug, thiserror::Error)]
+pub enum ApiError {
+    #[error("Número de features incompatível: esperado {expected}, recebido {received}")]
+    FeatureMismatch { expected: usize, received: usize },
+    
+    #[error("Requisição inválida: {0}")]
+    InvalidRequest(String),
+
+    #[error("Modelo não inicializado: {0}")]
+    ModelNotReady(String),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match &self {
+            ApiError::FeatureMismatch { .. } => StatusCode::BAD_REQUEST,
+            ApiError::InvalidRequest(_) => StatusCode::BAD_REQUEST,
+            ApiError::ModelNotReady(_) => StatusCode::BAD_REQUEST,
+        };
+        
+        (status, self.to_string()).into_response()
+    }
+}
+
+
+// Estruturas para processamento de solicitações
+
+#[derive(Debug, Deserialize)]
+pub struct PredictionRequest {
+    features: Vec<f64>,
+    learning_rate: Option<f64>,
+    reconfigure: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PredictionResponse {
+    pub predicted: u8,
+    pub confidence: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ModelConfigRequest {
+    num_features: usize,
+    learning_rate: f64,
+    #[serde(default)]
+    lambda: f64,
+    #[serde(default)]
+    tolerance: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrainingRequest {
+    features: Vec<Vec<f64>>,
+    targets: Vec<f64>,
+    epochs: usize,
+    // Sobrescreve a força da regularização L2 configurada no modelo
+    lambda: Option<f64>,
+    // Sobrescreve a tolerância de convergência configurada no modelo
+    tolerance: Option<f64>,
+    // Habilita a padronização das features ((x - média) / desvio) no treino
+    #[serde(default)]
+    standardize: bool,
+}
+
+/// Formatos de persistência suportados para os arquivos de modelo.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SerializationFormat {
+    #[default]
+    Json,
+    Msgpack,
+    Bincode,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SaveModelRequest {
+    filepath: String,
+    #[serde(default)]
+    format: SerializationFormat,
+    #[serde(default)]
+    compress: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LoadModelRequest {
+    filepath: String,
+    #[serde(default)]
+    format: SerializationFormat,
+    #[serde(default)]
+    compress: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EvaluateRequest {
+    features: Vec<Vec<f64>>,
+    targets: Vec<f64>,
+}
+
+/// Matriz de confusão 2×2 para classificação binária de risco de crédito.
+#[derive(Debug, Serialize)]
+pub struct ConfusionMatrix {
+    pub true_positive: usize,
+    pub false_positive: usize,
+    pub true_negative: usize,
+    pub false_negative: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EvaluateResponse {
+    pub accuracy: f64,
+    pub precision: f64,
+    pub recall: f64,
+    pub f1: f64,
+    pub confusion_matrix: ConfusionMatrix,
+    pub roc_auc: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TrainingJobResponse {
+    pub job_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LearningStatusResponse {
+    pub status: LearningStatus,
+    pub epochs_completed: usize,
+    pub total_epochs: usize,
+}
+
+// Handlers para os endpoints da API
+
+/// Processa requisições de predição
+pub async fn predict(
+    State(model): State<Arc<Mutex<LogisticRegression>>>,
+    Json(payload): Json<PredictionRequest>,
+) -> Result<Json<PredictionResponse>, ApiError> {
+    let mut model = model.lock().await;
+    
+    // Verificar se o modelo foi inicializado
+    if !model.initialized {
+        return Err(ApiError::ModelNotReady(
+            "O modelo não foi treinado ou carregado. Use /train ou /load primeiro.".to_string()
+        ));
+    }
+
+    // Validação do número de features
+    if payload.features.len() != model.weights.len() {
+        return Err(ApiError::FeatureMismatch { 
+            expected: model.weights.len(), 
+            received: payload.features.len() 
+        });
+    }
+    
+    // Reconfigura o modelo se solicitado
+    if let Some(lr) = payload.learning_rate {
+        if payload.reconfigure.unwrap_or(false) {
+            *model = LogisticRegression::new(model.weights.len(), lr);
+        }
+    }
+    
+    // Realiza a predição
+    let raw_prediction = model.predict_raw(&payload.features);
+    let prediction = model.predict(&payload.features);
+    
+    Ok(Json(PredictionResponse { 
+        predicted: prediction,
+        confidence: raw_prediction,
+    }))
+}
+
+/// Configura o modelo com novos parâmetros
+pub async fn configure_model(
+    State(model): State<Arc<Mutex<LogisticRegression>>>,
+    Json(config): Json<ModelConfigRequest>,
+) -> impl IntoResponse {
+    let mut model_lock = model.lock().await;
+    let mut new_model = LogisticRegression::new(config.num_features, config.learning_rate);
+    new_model.lambda = config.lambda;
+    new_model.tolerance = config.tolerance;
+    *model_lock = new_model;
+    StatusCode::OK
+}
+
+/// Inicia o treinamento do modelo em segundo plano.
+///
+/// Em vez de segurar o lock do modelo durante todas as épocas, o handler valida
+/// a requisição, clona o estado atual, agenda o treinamento numa blocking task e
+/// retorna imediatamente um `job_id`. O modelo treinado só é instalado no estado
+/// compartilhado ao final, de forma atômica, quando o aprendizado termina com sucesso.
+pub async fn train_model(
+    State(state): State<AppState>,
+    Json(payload): Json<TrainingRequest>,
+) -> Result<Json<TrainingJobResponse>, ApiError> {
+    // Clona o modelo atual para treinar fora do lock de predição
+    let mut training_model = {
+        let model = state.model.lock().await;
+
+        // Validações
+        if payload.features.is_empty() || payload.targets.is_empty() {
+            return Err(ApiError::InvalidRequest(
+                "Conjuntos de treinamento vazios".to_string()
+            ));
+        }
+
+        if payload.features.len() != payload.targets.len() {
+            return Err(ApiError::InvalidRequest(
+                format!("Número de amostras incompatível: {} features vs {} targets",
+                    payload.features.len(), payload.targets.len())
+            ));
+        }
+
+        // Verifica se cada amostra tem o número correto de features
+        for (i, sample) in payload.features.iter().enumerate() {
+            if sample.len() != model.weights.len() {
+                return Err(ApiError::InvalidRequest(
+                    format!("Amostra {} tem {} features, esperado {}",
+                        i, sample.len(), model.weights.len())
+                ));
+            }
+        }
+
+        model.clone()
+    };
+
+    // Permite ajustar os hiperparâmetros de regularização por requisição
+    if let Some(lambda) = payload.lambda {
+        training_model.lambda = lambda;
+    }
+    if let Some(tolerance) = payload.tolerance {
+        training_model.tolerance = tolerance;
+    }
+    training_model.standardize = payload.standardize;
+
+    // Registra o job como "Learning" antes de agendar o trabalho
+    let job_id = format!("job-{}", state.counter.fetch_add(1, Ordering::Relaxed));
+    let epochs = payload.epochs;
+    let progress = Arc::new(AtomicUsize::new(0));
+    {
+        let mut jobs = state.jobs.lock().await;
+        jobs.insert(
+            job_id.clone(),
+            LearningJob {
+                status: LearningStatus::Learning,
+                epochs_completed: progress.clone(),
+                total_epochs: epochs,
+            },
+        );
+    }
+
+    // Move os dados para a task de aprendizado
+    let features = payload.features;
+    let targets = payload.targets;
+    let jobs = state.jobs.clone();
+    let shared_model = state.model.clone();
+    let task_job_id = job_id.clone();
+    let task_progress = progress.clone();
+
+    tokio::spawn(async move {
+        let result = tokio::task::spawn_blocking(move || {
+            training_model.train_with_progress(&features, &targets, epochs, |completed| {
+                task_progress.store(completed, Ordering::Relaxed);
+            });
+            training_model
+        })
+        .await;
+
+        let mut jobs = jobs.lock().await;
+        match result {
+            Ok(trained) => {
+                // Instala o modelo treinado de forma atômica ("learning finished")
+                let mut model = shared_model.lock().await;
+                *model = trained;
+                if let Some(job) = jobs.get_mut(&task_job_id) {
+                    job.status = LearningStatus::Ready;
+                }
+            }
+            Err(err) => {
+                if let Some(job) = jobs.get_mut(&task_job_id) {
+                    job.status = LearningStatus::Failed(err.to_string());
+                }
+            }
+        }
+    });
+
+    Ok(Json(TrainingJobResponse { job_id }))
+}
+
+/// Retorna o status atual de um treinamento, incluindo o progresso em épocas.
+pub async fn training_status(
+    State(jobs): State<Arc<Mutex<HashMap<String, LearningJob>>>>,
+    Path(job_id): Path<String>,
+) -> Result<Json<LearningStatusResponse>, ApiError> {
+    let jobs = jobs.lock().await;
+    let job = jobs.get(&job_id).ok_or_else(|| {
+        ApiError::InvalidRequest(format!("Job de treinamento não encontrado: {}", job_id))
+    })?;
+
+    Ok(Json(LearningStatusResponse {
+        status: job.status.clone(),
+        epochs_completed: job.epochs_completed.load(Ordering::Relaxed),
+        total_epochs: job.total_epochs,
+    }))
+}
+
+/// Calcula a ROC-AUC pela identidade da estatística U de Mann–Whitney.
+///
+/// Ordena as amostras pelo score bruto, atribui postos (ranks) médios aos
+/// empates e aplica `AUC = (soma_dos_postos_dos_positivos - n_pos*(n_pos+1)/2)
+/// / (n_pos * n_neg)`. Retorna 0.5 quando só existe uma classe, caso em que a
+/// discriminação é indefinida.
+fn roc_auc(scores: &[(f64, f64)]) -> f64 {
+    let n_pos = scores.iter().filter(|(_, label)| *label > 0.5).count();
+    let n_neg = scores.len() - n_pos;
+    if n_pos == 0 || n_neg == 0 {
+        return 0.5;
+    }
+
+    // Ordena por score crescente para atribuir os postos (1 = menor score)
+    let mut indexed: Vec<(f64, f64)> = scores.to_vec();
+    indexed.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    // Atribui postos médios para scores empatados
+    let mut rank_sum_pos = 0.0;
+    let mut i = 0;
+    while i < indexed.len() {
+        let mut j = i;
+        while j + 1 < indexed.len() && indexed[j + 1].0 == indexed[i].0 {
+            j += 1;
+        }
+        // Postos de 1-based: posições i..=j recebem a média (i+1 + j+1) / 2
+        let average_rank = ((i + 1) + (j + 1)) as f64 / 2.0;
+        for item in &indexed[i..=j] {
+            if item.1 > 0.5 {
+                rank_sum_pos += average_rank;
+            }
+        }
+        i = j + 1;
+    }
+
+    let n_pos = n_pos as f64;
+    let n_neg = n_neg as f64;
+    (rank_sum_pos - n_pos * (n_pos + 1.0) / 2.0) / (n_pos * n_neg)
+}
+
+/// Precisão: fração de positivos previstos que eram de fato positivos.
+/// Retorna 0.0 quando não há nenhum positivo previsto (guarda de divisão por zero).
+fn precision(tp: usize, fp: usize) -> f64 {
+    if tp + fp == 0 {
+        0.0
+    } else {
+        tp as f64 / (tp + fp) as f64
+    }
+}
+
+/// Revocação: fração de positivos reais que foram recuperados.
+/// Retorna 0.0 quando não há nenhum positivo real (guarda de divisão por zero).
+fn recall(tp: usize, fn_: usize) -> f64 {
+    if tp + fn_ == 0 {
+        0.0
+    } else {
+        tp as f64 / (tp + fn_) as f64
+    }
+}
+
+/// Avalia o modelo sobre um conjunto rotulado e retorna as métricas de
+/// classificação usuais em risco de crédito.
+pub async fn evaluate_model(
+    State(model): State<Arc<Mutex<LogisticRegression>>>,
+    Json(payload): Json<EvaluateRequest>,
+) -> Result<Json<EvaluateResponse>, ApiError> {
+    let model = model.lock().await;
+
+    // Verificar se o modelo foi inicializado
+    if !model.initialized {
+        return Err(ApiError::ModelNotReady(
+            "O modelo não foi treinado ou carregado. Use /train ou /load primeiro.".to_string(),
+        ));
+    }
+
+    // Validações (mesmas regras de /train)
+    if payload.features.is_empty() || payload.targets.is_empty() {
+        return Err(ApiError::InvalidRequest(
+            "Conjuntos de avaliação vazios".to_string(),
+        ));
+    }
+
+    if payload.features.len() != payload.targets.len() {
+        return Err(ApiError::InvalidRequest(format!(
+            "Número de amostras incompatível: {} features vs {} targets",
+            payload.features.len(),
+            payload.targets.len()
+        )));
+    }
+
+    for (i, sample) in payload.features.iter().enumerate() {
+        if sample.len() != model.weights.len() {
+            return Err(ApiError::InvalidRequest(format!(
+                "Amostra {} tem {} features, esperado {}",
+                i,
+                sample.len(),
+                model.weights.len()
+            )));
+        }
+    }
+
+    // Executa as predições e acumula a matriz de confusão
+    let mut tp = 0usize;
+    let mut fp = 0usize;
+    let mut tn = 0usize;
+    let mut fn_ = 0usize;
+    let mut scores: Vec<(f64, f64)> = Vec::with_capacity(payload.features.len());
+
+    for (features, &target) in payload.features.iter().zip(payload.targets.iter()) {
+        let raw = model.predict_raw(features);
+        scores.push((raw, target));
+
+        let predicted_positive = raw > 0.5;
+        let actual_positive = target > 0.5;
+        match (predicted_positive, actual_positive) {
+            (true, true) => tp += 1,
+            (true, false) => fp += 1,
+            (false, false) => tn += 1,
+            (false, true) => fn_ += 1,
+        }
+    }
+
+    let total = payload.features.len() as f64;
+    let accuracy = (tp + tn) as f64 / total;
+    let precision = precision(tp, fp);
+    let recall = recall(tp, fn_);
+    let f1 = if precision + recall == 0.0 {
+        0.0
+    } else {
+        2.0 * precision * recall / (precision + recall)
+    };
+
+    Ok(Json(EvaluateResponse {
+        accuracy,
+        precision,
+        recall,
+        f1,
+        confusion_matrix: ConfusionMatrix {
+            true_positive: tp,
+            false_positive: fp,
+            true_negative: tn,
+            false_negative: fn_,
+        },
+        roc_auc: roc_auc(&scores),
+    }))
+}
+
+/// Serializa o modelo para bytes no formato solicitado.
+fn serialize_model(
+    model: &LogisticRegression,
+    format: SerializationFormat,
+) -> Result<Vec<u8>, ApiError> {
+    let bytes = match format {
+        SerializationFormat::Json => serde_json::to_vec(model)
+            .map_err(|e| ApiError::InvalidRequest(format!("Erro ao serializar modelo: {}", e)))?,
+        SerializationFormat::Msgpack => rmp_serde::to_vec(model)
+            .map_err(|e| ApiError::InvalidRequest(format!("Erro ao serializar modelo: {}", e)))?,
+        SerializationFormat::Bincode => bincode::serialize(model)
+            .map_err(|e| ApiError::InvalidRequest(format!("Erro ao serializar modelo: {}", e)))?,
+    };
+    Ok(bytes)
+}
+
+/// Deserializa o modelo a partir dos bytes no formato informado.
+fn deserialize_model(
+    bytes: &[u8],
+    format: SerializationFormat,
+) -> Result<LogisticRegression, ApiError> {
+    let model = match format {
+        SerializationFormat::Json => serde_json::from_slice(bytes)
+            .map_err(|e| ApiError::InvalidRequest(format!("Erro ao deserializar modelo: {}", e)))?,
+        SerializationFormat::Msgpack => rmp_serde::from_slice(bytes)
+            .map_err(|e| ApiError::InvalidRequest(format!("Erro ao deserializar modelo: {}", e)))?,
+        SerializationFormat::Bincode => bincode::deserialize(bytes)
+            .map_err(|e| ApiError::InvalidRequest(format!("Erro ao deserializar modelo: {}", e)))?,
+    };
+    Ok(model)
+}
+
+/// Comprime bytes com gzip.
+fn gzip_compress(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+    use std::io::Write;
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder
+        .write_all(bytes)
+        .map_err(|e| ApiError::InvalidRequest(format!("Erro ao comprimir modelo: {}", e)))?;
+    encoder
+        .finish()
+        .map_err(|e| ApiError::InvalidRequest(format!("Erro ao comprimir modelo: {}", e)))
+}
+
+/// Descomprime bytes gzip.
+fn gzip_decompress(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+    use std::io::Read;
+    let mut decoder = flate2::read::GzDecoder::new(bytes);
+    let mut out = Vec::new();
+    decoder
+        .read_to_end(&mut out)
+        .map_err(|e| ApiError::InvalidRequest(format!("Erro ao descomprimir modelo: {}", e)))?;
+    Ok(out)
+}
+
+/// Salva o modelo em arquivo no formato (e compressão) solicitado
+pub async fn save_model(
+    State(model): State<Arc<Mutex<LogisticRegression>>>,
+    Json(payload): Json<SaveModelRequest>,
+) -> Result<StatusCode, ApiError> {
+    // Serializa o modelo no formato escolhido
+    let mut bytes = {
+        let model_lock = model.lock().await;
+        serialize_model(&model_lock, payload.format)?
+    };
+
+    // Opcionalmente comprime os bytes com gzip
+    if payload.compress {
+        bytes = gzip_compress(&bytes)?;
+    }
+
+    // Escreve no arquivo
+    tokio::fs::write(&payload.filepath, bytes)
+        .await
+        .map_err(|e| ApiError::InvalidRequest(format!("Erro ao salvar arquivo: {}", e)))?;
+
+    Ok(StatusCode::OK)
+}
+
+/// Carrega o modelo a partir de um arquivo no formato (e compressão) informado
+pub async fn load_model(
+    State(model): State<Arc<Mutex<LogisticRegression>>>,
+    Json(payload): Json<LoadModelRequest>,
+) -> Result<StatusCode, ApiError> {
+    // Lê o arquivo
+    let mut file_content = tokio::fs::read(&payload.filepath)
+        .await
+        .map_err(|e| ApiError::InvalidRequest(format!("Erro ao ler arquivo: {}", e)))?;
+
+    // Descomprime, se necessário, antes de deserializar
+    if payload.compress {
+        file_content = gzip_decompress(&file_content)?;
+    }
+
+    // Deserializa de acordo com o formato informado
+    let mut loaded_model = deserialize_model(&file_content, payload.format)?;
+
+    // Garantir que o modelo esteja marcado como inicializado
+    loaded_model.initialized = true;
+
+    // Substitui o modelo atual
+    let mut model_lock = model.lock().await;
+    *model_lock = loaded_model;
+
+    Ok(StatusCode::OK)
+}
+
+
+/// Configura as rotas para este módulo
+pub fn routes(model: Arc<Mutex<LogisticRegression>>) -> Router {
+    let state = AppState {
+        model,
+        jobs: Arc::new(Mutex::new(HashMap::new())),
+        counter: Arc::new(AtomicU64::new(0)),
+    };
+
+    Router::new()
+        .route("/predict", post(predict))
+		.route("/configure", post(configure_model))
+        .route("/train", post(train_model))
+        .route("/train/status/:job_id", get(training_status))
+        .route("/evaluate", post(evaluate_model))
+        .route("/save-model", post(save_model))
+        .route("/load-model", post(load_model))
+        .with_state(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roc_auc_perfect_separation() {
+        // Positivos sempre com score maior que os negativos => AUC = 1.0
+        let scores = vec![(0.9, 1.0), (0.8, 1.0), (0.4, 0.0), (0.1, 0.0)];
+        assert_eq!(roc_auc(&scores), 1.0);
+    }
+
+    #[test]
+    fn test_roc_auc_with_ties() {
+        // Um positivo e um negativo empatados no mesmo score recebem posto médio,
+        // resultando em AUC = 0.5 para esse par.
+        let scores = vec![(0.5, 1.0), (0.5, 0.0)];
+        assert_eq!(roc_auc(&scores), 0.5);
+
+        // Empate parcial: dois positivos e um negativo, com um positivo empatado
+        // com o negativo no score mais baixo.
+        let scores = vec![(0.9, 1.0), (0.5, 1.0), (0.5, 0.0)];
+        // Postos: 0.5/0.5 => posto médio 1.5; 0.9 => posto 3.
+        // rank_sum_pos = 1.5 + 3 = 4.5; AUC = (4.5 - 2*3/2) / (2*1) = 0.75
+        assert_eq!(roc_auc(&scores), 0.75);
+    }
+
+    #[test]
+    fn test_roc_auc_single_class_is_half() {
+        // Sem positivos (ou sem negativos) a discriminação é indefinida => 0.5
+        let only_neg = vec![(0.3, 0.0), (0.7, 0.0)];
+        assert_eq!(roc_auc(&only_neg), 0.5);
+
+        let only_pos = vec![(0.3, 1.0), (0.7, 1.0)];
+        assert_eq!(roc_auc(&only_pos), 0.5);
+    }
+
+    #[test]
+    fn test_precision_recall_zero_guards() {
+        // Nenhum positivo previsto => precisão 0.0 em vez de divisão por zero
+        assert_eq!(precision(0, 0), 0.0);
+        // Nenhum positivo real => revocação 0.0 em vez de divisão por zero
+        assert_eq!(recall(0, 0), 0.0);
+
+        assert_eq!(precision(3, 1), 0.75);
+        assert_eq!(recall(3, 1), 0.75);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,2 @@
+pub mod health_check_route;
+pub mod logistic_regression_route;
@@ -13,7 +13,7 @@ mod tests {
         assert_eq!(model.weights, vec![0.0, 0.0, 0.0]);
         assert_eq!(model.bias, 0.0);
         assert_eq!(model.learning_rate, 0.01);
-        assert_eq!(model.initialized, false);
+        assert!(!model.initialized);
     }
 
     #[test]
@@ -119,6 +119,100 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_tolerance_early_stop() {
+        // Dados linearmente separáveis simples
+        let x = vec![vec![0.0], vec![1.0], vec![2.0], vec![3.0]];
+        let y = vec![0.0, 0.0, 1.0, 1.0];
+
+        // Sem tolerância, todas as épocas devem ser executadas
+        let mut baseline = LogisticRegression::new(1, 0.1);
+        let mut baseline_epochs = 0usize;
+        baseline.train_with_progress(&x, &y, 50, |completed| baseline_epochs = completed);
+        assert_eq!(baseline_epochs, 50);
+
+        // Com uma tolerância alta, o treino deve parar antes do limite de épocas
+        let mut early = LogisticRegression::new(1, 0.1);
+        early.tolerance = 1.0;
+        let mut early_epochs = 0usize;
+        early.train_with_progress(&x, &y, 50, |completed| early_epochs = completed);
+        assert!(
+            early_epochs < 50,
+            "a parada antecipada deveria interromper antes de 50 épocas, parou em {}",
+            early_epochs
+        );
+    }
+
+    #[test]
+    fn test_l2_shrinks_weights() {
+        let x = vec![vec![1.0, 2.0], vec![2.0, 1.0], vec![3.0, 3.0], vec![0.0, 1.0]];
+        let y = vec![0.0, 0.0, 1.0, 0.0];
+
+        let mut plain = LogisticRegression::new(2, 0.1);
+        plain.train(&x, &y, 200);
+
+        let mut ridge = LogisticRegression::new(2, 0.1);
+        ridge.lambda = 0.5;
+        ridge.train(&x, &y, 200);
+
+        let plain_norm: f64 = plain.weights.iter().map(|w| w * w).sum();
+        let ridge_norm: f64 = ridge.weights.iter().map(|w| w * w).sum();
+        assert!(
+            ridge_norm < plain_norm,
+            "a penalização L2 deveria encolher os pesos: {} >= {}",
+            ridge_norm,
+            plain_norm
+        );
+    }
+
+    #[test]
+    fn test_standardization_round_trip() {
+        // Features em escalas bem diferentes para exercitar a padronização
+        let x = vec![
+            vec![1000.0, 0.1],
+            vec![2000.0, 0.2],
+            vec![3000.0, 0.3],
+            vec![4000.0, 0.9],
+        ];
+        let y = vec![0.0, 0.0, 1.0, 1.0];
+
+        let mut model = LogisticRegression::new(2, 0.1);
+        model.standardize = true;
+        model.train(&x, &y, 100);
+
+        // As estatísticas por feature devem ter sido aprendidas
+        assert_eq!(model.means.len(), 2);
+        assert_eq!(model.stds.len(), 2);
+
+        // Um modelo carregado deve escalar a entrada de forma idêntica ao treino
+        let serialized = serde_json::to_string(&model).unwrap();
+        let loaded: LogisticRegression = serde_json::from_str(&serialized).unwrap();
+
+        let sample = vec![2500.0, 0.5];
+        assert_relative_eq!(
+            loaded.predict_raw(&sample),
+            model.predict_raw(&sample),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_standardization_zero_std_guard() {
+        // A primeira feature é constante => desvio padrão 0, armazenado como 0.0
+        // e substituído por 1.0 apenas ao escalar as features
+        let x = vec![vec![5.0, 0.0], vec![5.0, 1.0], vec![5.0, 2.0]];
+        let y = vec![0.0, 1.0, 1.0];
+
+        let mut model = LogisticRegression::new(2, 0.1);
+        model.standardize = true;
+        model.train(&x, &y, 10);
+
+        assert_eq!(model.stds[0], 0.0);
+        // Não deve produzir NaN/infinito apesar do desvio zero
+        let raw = model.predict_raw(&[5.0, 1.0]);
+        assert!(raw.is_finite());
+    }
+
     #[test]
     fn test_serialization() {
         let mut model = LogisticRegression::new(2, 0.01);